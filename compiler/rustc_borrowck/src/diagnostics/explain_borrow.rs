@@ -2,6 +2,7 @@
 
 use rustc_errors::{Applicability, Diagnostic};
 use rustc_hir as hir;
+use rustc_hir::def_id::DefId;
 use rustc_hir::intravisit::Visitor;
 use rustc_index::IndexSlice;
 use rustc_infer::infer::NllRegionVariableOrigin;
@@ -10,7 +11,7 @@
     Operand, Place, Rvalue, Statement, StatementKind, TerminatorKind,
 };
 use rustc_middle::ty::adjustment::PointerCoercion;
-use rustc_middle::ty::{self, RegionVid, TyCtxt};
+use rustc_middle::ty::{self, RegionVid, Ty, TyCtxt};
 use rustc_span::symbol::{kw, Symbol};
 use rustc_span::{sym, DesugaringKind, Span};
 use rustc_trait_selection::traits::error_reporting::FindExprBySpan;
@@ -31,6 +32,7 @@ pub(crate) enum BorrowExplanation<'tcx> {
         drop_loc: Location,
         dropped_local: Local,
         should_note_order: bool,
+        dtor_field: Option<DtorField<'tcx>>,
     },
     MustBeValidFor {
         category: ConstraintCategory<'tcx>,
@@ -43,6 +45,15 @@ pub(crate) enum BorrowExplanation<'tcx> {
     Unexplained,
 }
 
+/// The field of a dropped local whose destructor might use the borrow, if it's not the
+/// destructor of the local itself. See [`find_dtor_field`].
+#[derive(Debug)]
+pub(crate) struct DtorField<'tcx> {
+    /// The names of the fields leading from the local to the field, e.g. `["a", "0"]`.
+    path: Vec<String>,
+    ty: Ty<'tcx>,
+}
+
 #[derive(Clone, Copy, Debug)]
 pub(crate) enum LaterUseKind {
     TraitCapture,
@@ -161,6 +172,7 @@ pub(crate) fn add_explanation_to_diagnostic(
                 drop_loc,
                 dropped_local,
                 should_note_order,
+                ref dtor_field,
             } => {
                 let local_decl = &body.local_decls[dropped_local];
                 let mut ty = local_decl.ty;
@@ -201,6 +213,14 @@ pub(crate) fn add_explanation_to_diagnostic(
                                  in the opposite order they are defined",
                             );
                         }
+                        if let Some(DtorField { path, ty }) = dtor_field {
+                            let (dtor_desc, type_desc) = describe_dtor(tcx, *ty);
+                            let path = path.join(".");
+                            err.note(format!(
+                                "the {borrow_desc}borrow might be used by the {dtor_desc} for \
+                                 {type_desc}, which runs when `{local_name}.{path}` is dropped",
+                            ));
+                        }
                     }
                     _ => {
                         err.span_label(
@@ -217,6 +237,16 @@ pub(crate) fn add_explanation_to_diagnostic(
                         );
                         err.span_label(body.source_info(drop_loc).span, message);
 
+                        if let Some(DtorField { path, ty }) = dtor_field {
+                            let (dtor_desc, type_desc) = describe_dtor(tcx, *ty);
+                            let path = path.join(".");
+                            err.note(format!(
+                                "the {borrow_desc}borrow might be used by the {dtor_desc} for \
+                                 {type_desc}, which runs when field `{path}` of that temporary \
+                                 is dropped",
+                            ));
+                        }
+
                         if let LocalInfo::BlockTailTemp(info) = local_decl.local_info() {
                             if info.tail_result_is_ignored {
                                 // #85581: If the first mutable borrow's scope contains
@@ -426,6 +456,12 @@ pub(crate) fn explain_why_borrow_contains_point(
                     drop_loc: location,
                     dropped_local: local,
                     should_note_order,
+                    dtor_field: find_dtor_field(
+                        tcx,
+                        self.mir_def_id().to_def_id(),
+                        body.local_decls[local].ty,
+                        region_sub,
+                    ),
                 }
             }
 
@@ -660,3 +696,79 @@ fn was_captured_by_trait_object(&self, borrow: &BorrowData<'tcx>) -> bool {
         false
     }
 }
+
+/// Finds the field of a value of type `ty` whose destructor might use `region`, if that's
+/// not the destructor of `ty` itself.
+///
+/// This only looks through the fields of structs and tuples without a destructor which are
+/// accessible from `body_def_id`, so that the field can be named in diagnostics. The
+/// complete set of types whose destructors may run is computed by `dropck_outlives`, but
+/// this is only needed once an error is reported, so we recompute it here instead.
+fn find_dtor_field<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    body_def_id: DefId,
+    ty: Ty<'tcx>,
+    region: RegionVid,
+) -> Option<DtorField<'tcx>> {
+    let mut path = Vec::new();
+    let ty = find_dtor_field_inner(tcx, body_def_id, ty, region, &mut path)?;
+    (!path.is_empty()).then_some(DtorField { path, ty })
+}
+
+fn find_dtor_field_inner<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    body_def_id: DefId,
+    ty: Ty<'tcx>,
+    region: RegionVid,
+    path: &mut Vec<String>,
+) -> Option<Ty<'tcx>> {
+    let mentions_region = |arg: ty::GenericArg<'tcx>| {
+        tcx.any_free_region_meets(&arg, |r| matches!(*r, ty::ReVar(vid) if vid == region))
+    };
+    if !mentions_region(ty.into()) {
+        return None;
+    }
+
+    let mut find_in_field = |name: String, field_ty: Ty<'tcx>| {
+        path.push(name);
+        let found = find_dtor_field_inner(tcx, body_def_id, field_ty, region, path);
+        if found.is_none() {
+            path.pop();
+        }
+        found
+    };
+
+    match *ty.kind() {
+        ty::Adt(def, args) if def.has_dtor(tcx) => {
+            let dtor_uses_region = tcx
+                .destructor_constraints(def)
+                .into_iter()
+                .any(|arg| mentions_region(ty::EarlyBinder::bind(arg).instantiate(tcx, args)));
+            dtor_uses_region.then_some(ty)
+        }
+        ty::Adt(def, args) if def.is_struct() => {
+            def.non_enum_variant().fields.iter().find_map(|field| {
+                if !field.vis.is_accessible_from(body_def_id, tcx) {
+                    return None;
+                }
+                find_in_field(field.name.to_string(), field.ty(tcx, args))
+            })
+        }
+        ty::Tuple(tys) => {
+            tys.iter().enumerate().find_map(|(i, field_ty)| find_in_field(i.to_string(), field_ty))
+        }
+        // A trait object may have a destructor, which may use anything it contains.
+        ty::Dynamic(..) => Some(ty),
+        _ => None,
+    }
+}
+
+/// Describes the destructor of a field found by [`find_dtor_field`].
+fn describe_dtor<'tcx>(tcx: TyCtxt<'tcx>, ty: Ty<'tcx>) -> (&'static str, String) {
+    match ty.kind() {
+        ty::Adt(adt, _args) if adt.has_dtor(tcx) => {
+            ("`Drop` code", format!("type `{}`", tcx.def_path_str(adt.did())))
+        }
+        _ => ("destructor", format!("type `{ty}`")),
+    }
+}
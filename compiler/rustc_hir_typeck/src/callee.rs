@@ -3,6 +3,7 @@
 use super::{Expectation, FnCtxt, TupleArgumentsFlag};
 
 use crate::errors;
+use rustc_ast as ast;
 use rustc_ast::util::parser::PREC_POSTFIX;
 use rustc_errors::{Applicability, Diagnostic, ErrorGuaranteed, StashKey};
 use rustc_hir as hir;
@@ -403,6 +404,63 @@ fn confirm_builtin_call(
                             .emit();
                     }
                 }
+
+                // Unit testing: function items annotated with
+                // `#[rustc_dump_evaluation_tree]` also print the nested obligations
+                // evaluated for each of their where clauses, to let us test the
+                // evaluation trees used to explain evaluation results.
+                #[allow(rustc::untranslatable_diagnostic)]
+                #[allow(rustc::diagnostic_outside_of_impl)]
+                if let Some(attr) = self.tcx.get_attr(def_id, sym::rustc_dump_evaluation_tree) {
+                    let limit = match attr.meta_item_list().as_deref() {
+                        None => Some(usize::MAX),
+                        Some([arg]) => match arg.lit().map(|lit| &lit.kind) {
+                            Some(&ast::LitKind::Int(limit, _)) => usize::try_from(limit).ok(),
+                            _ => None,
+                        },
+                        Some(_) => None,
+                    };
+                    let limit = limit.unwrap_or_else(|| {
+                        self.tcx.sess.span_err(attr.span, "expected a single integer limit");
+                        usize::MAX
+                    });
+                    let predicates = self.tcx.predicates_of(def_id);
+                    let predicates = predicates.instantiate(self.tcx, args);
+                    for (predicate, predicate_span) in predicates {
+                        let obligation = Obligation::new(
+                            self.tcx,
+                            ObligationCause::dummy_with_span(callee_expr.span),
+                            self.param_env,
+                            predicate,
+                        );
+                        let (result, tree) = self.evaluate_obligation_with_tree(&obligation, limit);
+                        let mut err = self.tcx.sess.struct_span_err(
+                            callee_expr.span,
+                            format!("evaluate({predicate:?}) = {result:?}"),
+                        );
+                        err.span_label(predicate_span, "predicate");
+                        if let Some(tree) = tree {
+                            for node in &tree.nodes {
+                                let indent = "  ".repeat(node.recursion_depth);
+                                let predicate = node.predicate;
+                                match node.result {
+                                    Some(result) => {
+                                        err.note(format!("{indent}{predicate:?} = {result:?}"))
+                                    }
+                                    None => err.note(format!("{indent}{predicate:?}")),
+                                };
+                            }
+                            if tree.truncated {
+                                err.note(format!(
+                                    "the evaluation tree was truncated to {limit} nodes"
+                                ));
+                            }
+                        } else {
+                            err.note("evaluation trees are not supported by the new trait solver");
+                        }
+                        err.emit();
+                    }
+                }
                 (fn_sig, Some(def_id))
             }
             // FIXME(effects): these arms should error because we can't enforce them
@@ -1,10 +1,12 @@
 use rustc_middle::ty::fold::{TypeFoldable, TypeFolder, TypeSuperFoldable};
+use rustc_middle::ty::visit::TypeVisitableExt;
 use rustc_middle::ty::{self, ConstVid, FloatVid, IntVid, RegionVid, Ty, TyCtxt, TyVid};
 
 use super::type_variable::TypeVariableOrigin;
 use super::InferCtxt;
 use super::{ConstVariableOrigin, RegionVariableOrigin, UnificationTable};
 
+use rustc_data_structures::fx::FxHashMap;
 use rustc_data_structures::snapshot_vec as sv;
 use rustc_data_structures::unify as ut;
 use ut::UnifyKey;
@@ -164,6 +166,27 @@ pub fn fudge_inference_if_ok<T, E, F>(&self, f: F) -> Result<T, E>
             Ok(value.fold_with(&mut fudger))
         }
     }
+
+    /// Returns a folder which replaces the inference variables which no longer exist,
+    /// because the snapshot they were created in has been rolled back, with fresh
+    /// types and consts. See [`RolledBackVarReplacer::replace`].
+    ///
+    /// Unlike `fudge_inference_if_ok`, this doesn't need to know which snapshot the
+    /// variables were created in, and it doesn't create any new inference variables.
+    /// A variable index which was reused by a variable created after the rollback is
+    /// not detected, however, so the replacer should be created before creating any
+    /// new variables.
+    pub fn rolled_back_var_replacer(&self) -> RolledBackVarReplacer<'tcx> {
+        RolledBackVarReplacer {
+            tcx: self.tcx,
+            variable_lengths: self.variable_lengths(),
+            effect_var_len: self.inner.borrow_mut().effect_unification_table().len(),
+            ty_fresh_count: 0,
+            const_fresh_count: 0,
+            ty_fresh_map: Default::default(),
+            const_fresh_map: Default::default(),
+        }
+    }
 }
 
 pub struct InferenceFudger<'a, 'tcx> {
@@ -246,3 +269,120 @@ fn fold_const(&mut self, ct: ty::Const<'tcx>) -> ty::Const<'tcx> {
         }
     }
 }
+
+/// Replaces rolled back inference variables with fresh types and consts, like the ones
+/// created by the [`TypeFreshener`](super::TypeFreshener), and rolled back region variables
+/// with `'erased`.
+///
+/// The result must not be related to anything else. It is only meant to be displayed, e.g.
+/// in diagnostics.
+pub struct RolledBackVarReplacer<'tcx> {
+    tcx: TyCtxt<'tcx>,
+    variable_lengths: VariableLengths,
+    effect_var_len: usize,
+    ty_fresh_count: u32,
+    const_fresh_count: u32,
+    ty_fresh_map: FxHashMap<ty::InferTy, Ty<'tcx>>,
+    const_fresh_map: FxHashMap<ty::InferConst<'tcx>, ty::Const<'tcx>>,
+}
+
+impl<'tcx> RolledBackVarReplacer<'tcx> {
+    /// Replaces the rolled back inference variables in `value` with fresh types and consts.
+    ///
+    /// Separate snapshots reuse the same variable indices once the previous one has
+    /// been rolled back, so the same index in two values may stand for two unrelated
+    /// variables. Each call therefore numbers its fresh types and consts from zero, so
+    /// `value` should only contain variables from a single point in time.
+    ///
+    /// `value` can't be resolved once its variables have been rolled back, so it should
+    /// be resolved with `resolve_vars_if_possible` while they still exist.
+    pub fn replace<T: TypeFoldable<TyCtxt<'tcx>>>(&mut self, value: T) -> T {
+        if !value.has_infer() {
+            return value;
+        }
+
+        self.ty_fresh_count = 0;
+        self.const_fresh_count = 0;
+        self.ty_fresh_map.clear();
+        self.const_fresh_map.clear();
+        value.fold_with(self)
+    }
+
+    fn fresh_ty(
+        &mut self,
+        key: ty::InferTy,
+        mk_fresh: fn(TyCtxt<'tcx>, u32) -> Ty<'tcx>,
+    ) -> Ty<'tcx> {
+        let tcx = self.tcx;
+        let count = &mut self.ty_fresh_count;
+        *self.ty_fresh_map.entry(key).or_insert_with(|| {
+            let index = *count;
+            *count += 1;
+            mk_fresh(tcx, index)
+        })
+    }
+
+    fn fresh_const(&mut self, key: ty::InferConst<'tcx>, ty: Ty<'tcx>) -> ty::Const<'tcx> {
+        let tcx = self.tcx;
+        let count = &mut self.const_fresh_count;
+        *self.const_fresh_map.entry(key).or_insert_with(|| {
+            let index = *count;
+            *count += 1;
+            ty::Const::new_infer(tcx, ty::InferConst::Fresh(index), ty)
+        })
+    }
+}
+
+impl<'tcx> TypeFolder<TyCtxt<'tcx>> for RolledBackVarReplacer<'tcx> {
+    fn interner(&self) -> TyCtxt<'tcx> {
+        self.tcx
+    }
+
+    fn fold_ty(&mut self, ty: Ty<'tcx>) -> Ty<'tcx> {
+        match *ty.kind() {
+            ty::Infer(key @ ty::TyVar(vid))
+                if vid.as_usize() >= self.variable_lengths.type_var_len =>
+            {
+                self.fresh_ty(key, Ty::new_fresh)
+            }
+            ty::Infer(key @ ty::IntVar(vid))
+                if vid.index() >= self.variable_lengths.int_var_len =>
+            {
+                self.fresh_ty(key, Ty::new_fresh_int)
+            }
+            ty::Infer(key @ ty::FloatVar(vid))
+                if vid.index() >= self.variable_lengths.float_var_len =>
+            {
+                self.fresh_ty(key, Ty::new_fresh_float)
+            }
+            _ if ty.has_infer() => ty.super_fold_with(self),
+            _ => ty,
+        }
+    }
+
+    fn fold_region(&mut self, r: ty::Region<'tcx>) -> ty::Region<'tcx> {
+        if let ty::ReVar(vid) = *r
+            && vid.index() >= self.variable_lengths.region_constraints_len
+        {
+            return self.tcx.lifetimes.re_erased;
+        }
+        r
+    }
+
+    fn fold_const(&mut self, ct: ty::Const<'tcx>) -> ty::Const<'tcx> {
+        match ct.kind() {
+            ty::ConstKind::Infer(key @ ty::InferConst::Var(vid))
+                if vid.index as usize >= self.variable_lengths.const_var_len =>
+            {
+                self.fresh_const(key, ct.ty())
+            }
+            ty::ConstKind::Infer(key @ ty::InferConst::EffectVar(vid))
+                if vid.index as usize >= self.effect_var_len =>
+            {
+                self.fresh_const(key, ct.ty())
+            }
+            _ if ct.has_infer() => ct.super_fold_with(self),
+            _ => ct,
+        }
+    }
+}
@@ -1,5 +1,6 @@
 pub use self::at::DefineOpaqueTypes;
 pub use self::freshen::TypeFreshener;
+pub use self::fudge::RolledBackVarReplacer;
 pub use self::lexical_region_resolve::RegionResolutionError;
 pub use self::LateBoundRegionConversionTime::*;
 pub use self::RegionVariableOrigin::*;
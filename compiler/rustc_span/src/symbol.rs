@@ -1361,6 +1361,7 @@
         rustc_doc_primitive,
         rustc_dummy,
         rustc_dump_env_program_clauses,
+        rustc_dump_evaluation_tree,
         rustc_dump_program_clauses,
         rustc_dump_user_args,
         rustc_dump_vtable,
@@ -4,7 +4,7 @@
 
 use rustc_data_structures::fx::FxHashSet;
 use rustc_middle::traits::query::{DropckConstraint, DropckOutlivesResult};
-use rustc_middle::ty::{self, EarlyBinder, ParamEnvAnd, Ty, TyCtxt};
+use rustc_middle::ty::{self, EarlyBinder, ParamEnvAnd, Ty, TyCtxt, TypeVisitableExt};
 use rustc_span::source_map::{Span, DUMMY_SP};
 
 /// This returns true if the type `ty` is "trivial" for
@@ -42,6 +42,9 @@ pub fn trivial_dropck_outlives<'tcx>(tcx: TyCtxt<'tcx>, ty: Ty<'tcx>) -> bool {
         | ty::Foreign(..)
         | ty::Error(_) => true,
 
+        // An empty array never drops any of its elements.
+        ty::Array(_, size) if size.try_to_target_usize(tcx) == Some(0) => true,
+
         // [T; N] and [T] have same properties as T.
         ty::Array(ty, _) | ty::Slice(ty) => trivial_dropck_outlives(tcx, *ty),
 
@@ -164,6 +167,14 @@ pub fn compute_dropck_outlives_inner<'tcx>(
                 // function.
                 ty::Param(..) => {}
 
+                // An array whose length is still generic. We can't tell
+                // whether it is empty, so its elements may get dropped.
+                ty::Array(ety, size) if size.has_param() => {
+                    if ty_set.insert(*ety) {
+                        ty_stack.push((*ety, depth + 1));
+                    }
+                }
+
                 // A projection that we couldn't resolve - it
                 // might have a destructor.
                 ty::Alias(..) => {
@@ -221,6 +232,16 @@ pub fn dtorck_constraint_for_ty_inner<'tcx>(
             // these types never have a destructor
         }
 
+        ty::Array(_, size) if size.try_to_target_usize(tcx) == Some(0) => {
+            // an empty array never drops any of its elements
+        }
+
+        ty::Array(_, size) if size.has_param() => {
+            // we can't tell whether the array is empty until its
+            // length is instantiated, so pass it forward
+            constraints.dtorck_types.push(ty);
+        }
+
         ty::Array(ety, _) | ty::Slice(ety) => {
             // single-element containers, behave like their element
             rustc_data_structures::stack::ensure_sufficient_stack(|| {
@@ -300,9 +321,15 @@ pub fn dtorck_constraint_for_ty_inner<'tcx>(
                 tcx.at(span).adt_dtorck_constraint(def.did())?;
             // FIXME: we can try to recursively `dtorck_constraint_on_ty`
             // there, but that needs some way to handle cycles.
-            constraints
-                .dtorck_types
-                .extend(dtorck_types.iter().map(|t| EarlyBinder::bind(*t).instantiate(tcx, args)));
+            //
+            // Types which became trivial after instantiation, e.g. a
+            // `T` instantiated with `&'a u32`, do not need to be revisited.
+            constraints.dtorck_types.extend(
+                dtorck_types
+                    .iter()
+                    .map(|t| EarlyBinder::bind(*t).instantiate(tcx, args))
+                    .filter(|&t| !trivial_dropck_outlives(tcx, t)),
+            );
             constraints
                 .outlives
                 .extend(outlives.iter().map(|t| EarlyBinder::bind(*t).instantiate(tcx, args)));
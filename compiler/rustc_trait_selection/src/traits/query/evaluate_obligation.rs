@@ -2,7 +2,8 @@
 
 use crate::infer::canonical::OriginalQueryValues;
 use crate::infer::InferCtxt;
-use crate::traits::{EvaluationResult, OverflowError, PredicateObligation, SelectionContext};
+use crate::traits::{EvaluationResult, EvaluationTree, OverflowError, PredicateObligation};
+use crate::traits::{SelectionContext, TraitQueryMode};
 
 pub trait InferCtxtExt<'tcx> {
     fn predicate_may_hold(&self, obligation: &PredicateObligation<'tcx>) -> bool;
@@ -19,6 +20,12 @@ fn evaluate_obligation(
         obligation: &PredicateObligation<'tcx>,
     ) -> Result<EvaluationResult, OverflowError>;
 
+    fn evaluate_obligation_with_tree(
+        &self,
+        obligation: &PredicateObligation<'tcx>,
+        limit: usize,
+    ) -> (Result<EvaluationResult, OverflowError>, Option<EvaluationTree<'tcx>>);
+
     // Helper function that canonicalizes and runs the query. If an
     // overflow results, we re-run it in the local context so we can
     // report a nice error.
@@ -95,6 +102,37 @@ fn evaluate_obligation(
         }
     }
 
+    /// Like `evaluate_obligation`, but also returns the tree of the nested
+    /// obligations which were evaluated, recording at most `limit` of them.
+    ///
+    /// This always evaluates the obligation in the local context, as the query
+    /// result is cached and the nested obligations refer to our inference
+    /// variables. It is meant for diagnostics and tooling which need to explain
+    /// an evaluation result, so don't use it for anything else.
+    ///
+    /// The new solver doesn't record evaluation trees, so with it this doesn't
+    /// return a tree at all.
+    fn evaluate_obligation_with_tree(
+        &self,
+        obligation: &PredicateObligation<'tcx>,
+        limit: usize,
+    ) -> (Result<EvaluationResult, OverflowError>, Option<EvaluationTree<'tcx>>) {
+        if self.next_trait_solver() {
+            return (self.evaluate_obligation(obligation), None);
+        }
+
+        let mut selcx = SelectionContext::with_query_mode(self, TraitQueryMode::Canonical);
+        selcx.enable_tracking_evaluation_tree(limit);
+        let result = selcx.evaluate_root_obligation(obligation);
+        let mut tree = selcx.take_evaluation_tree();
+        // `evaluate_root_obligation` adjusts the result of the root obligation,
+        // e.g. if evaluating it did inference, so use the final result.
+        if let Some(root) = tree.nodes.first_mut() {
+            root.result = Some(result);
+        }
+        (result, Some(tree))
+    }
+
     // Helper function that canonicalizes and runs the query. If an
     // overflow results, we re-run it in the local context so we can
     // report a nice error.
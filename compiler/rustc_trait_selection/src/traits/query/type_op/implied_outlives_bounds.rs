@@ -1,8 +1,10 @@
 use crate::solve;
+use crate::traits::query::normalize::QueryNormalizeExt;
 use crate::traits::query::NoSolution;
 use crate::traits::wf;
-use crate::traits::ObligationCtxt;
+use crate::traits::{Normalized, ObligationCtxt};
 
+use rustc_data_structures::fx::{FxHashMap, FxIndexSet};
 use rustc_infer::infer::canonical::Canonical;
 use rustc_infer::infer::outlives::components::{push_outlives_components, Component};
 use rustc_infer::traits::query::OutlivesBound;
@@ -73,8 +75,28 @@ pub fn compute_implied_outlives_bounds_inner<'tcx>(
     let mut checked_wf_args = rustc_data_structures::fx::FxHashSet::default();
     let mut wf_args = vec![ty.into()];
 
-    let mut outlives_bounds: Vec<ty::OutlivesPredicate<ty::GenericArg<'tcx>, ty::Region<'tcx>>> =
-        vec![];
+    // `wf::obligations` does not normalize, so bounds which only show up after
+    // normalizing the projections in `ty` would be missed. We still keep `ty`
+    // itself around, as the bounds implied by the unnormalized type are relied
+    // upon as well.
+    //
+    // The nested obligations of the normalization relate the regions of the
+    // normalized type to the ones of `ty`, so we have to keep them around.
+    if ty.has_projections()
+        && let Ok(Normalized { value: normalized_ty, obligations }) =
+            ocx.infcx.at(&ObligationCause::dummy(), param_env).query_normalize(ty)
+        && normalized_ty != ty
+    {
+        debug!(?normalized_ty);
+        ocx.register_obligations(obligations);
+        wf_args.push(normalized_ty.into());
+    }
+
+    // Large signatures tend to require the same outlives predicates many times,
+    // so we deduplicate them here instead of repeatedly decomposing them below.
+    let mut outlives_bounds: FxIndexSet<
+        ty::OutlivesPredicate<ty::GenericArg<'tcx>, ty::Region<'tcx>>,
+    > = FxIndexSet::default();
 
     while let Some(arg) = wf_args.pop() {
         if !checked_wf_args.insert(arg) {
@@ -147,12 +169,16 @@ pub fn compute_implied_outlives_bounds_inner<'tcx>(
                 ty::PredicateKind::Clause(ty::ClauseKind::RegionOutlives(ty::OutlivesPredicate(
                     r_a,
                     r_b,
-                ))) => outlives_bounds.push(ty::OutlivesPredicate(r_a.into(), r_b)),
+                ))) => {
+                    outlives_bounds.insert(ty::OutlivesPredicate(r_a.into(), r_b));
+                }
 
                 ty::PredicateKind::Clause(ty::ClauseKind::TypeOutlives(ty::OutlivesPredicate(
                     ty_a,
                     r_b,
-                ))) => outlives_bounds.push(ty::OutlivesPredicate(ty_a.into(), r_b)),
+                ))) => {
+                    outlives_bounds.insert(ty::OutlivesPredicate(ty_a.into(), r_b));
+                }
             }
         }
     }
@@ -166,11 +192,16 @@ pub fn compute_implied_outlives_bounds_inner<'tcx>(
 
     // We lazily compute the outlives components as
     // `select_all_or_error` constrains inference variables.
-    let mut implied_bounds = Vec::new();
+    //
+    // The same type is frequently required to outlive multiple regions, so we
+    // only decompose each type into its components once.
+    let mut components_cache: FxHashMap<Ty<'tcx>, SmallVec<[Component<'tcx>; 4]>> =
+        FxHashMap::default();
+    let mut implied_bounds = FxIndexSet::default();
     for ty::OutlivesPredicate(a, r_b) in outlives_bounds {
         match a.unpack() {
             ty::GenericArgKind::Lifetime(r_a) => {
-                implied_bounds.push(OutlivesBound::RegionSubRegion(r_b, r_a))
+                implied_bounds.insert(OutlivesBound::RegionSubRegion(r_b, r_a));
             }
             ty::GenericArgKind::Type(ty_a) => {
                 let mut ty_a = ocx.infcx.resolve_vars_if_possible(ty_a);
@@ -182,15 +213,18 @@ pub fn compute_implied_outlives_bounds_inner<'tcx>(
                     )
                     .map_err(|_errs| NoSolution)?;
                 }
-                let mut components = smallvec![];
-                push_outlives_components(tcx, ty_a, &mut components);
+                let components = components_cache.entry(ty_a).or_insert_with(|| {
+                    let mut components = smallvec![];
+                    push_outlives_components(tcx, ty_a, &mut components);
+                    components
+                });
                 implied_bounds.extend(implied_bounds_from_components(r_b, components))
             }
             ty::GenericArgKind::Const(_) => unreachable!(),
         }
     }
 
-    Ok(implied_bounds)
+    Ok(implied_bounds.into_iter().collect())
 }
 
 /// When we have an implied bound that `T: 'a`, we can further break
@@ -199,12 +233,12 @@ pub fn compute_implied_outlives_bounds_inner<'tcx>(
 /// those relationships.
 fn implied_bounds_from_components<'tcx>(
     sub_region: ty::Region<'tcx>,
-    sup_components: SmallVec<[Component<'tcx>; 4]>,
+    sup_components: &[Component<'tcx>],
 ) -> Vec<OutlivesBound<'tcx>> {
     sup_components
-        .into_iter()
+        .iter()
         .filter_map(|component| {
-            match component {
+            match *component {
                 Component::Region(r) => Some(OutlivesBound::RegionSubRegion(sub_region, r)),
                 Component::Param(p) => Some(OutlivesBound::RegionSubParam(sub_region, p)),
                 Component::Alias(p) => Some(OutlivesBound::RegionSubAlias(sub_region, p)),
@@ -98,6 +98,49 @@ pub fn intercrate_ambiguity_hint(&self) -> String {
     }
 }
 
+/// The obligations evaluated by a [`SelectionContext`] while tracking them was enabled via
+/// [`SelectionContext::enable_tracking_evaluation_tree`], in the order in which their
+/// evaluation started. The nested obligations of a node are the nodes following it with a
+/// greater recursion depth.
+///
+/// Only obligations evaluated by that selection context itself are recorded. Its evaluation
+/// caches are not used until the tree reaches its size limit, so a recorded node also has the
+/// nested obligations it evaluated, unless they are part of a cycle. Obligations which selection
+/// evaluates through the `evaluate_obligation` query instead, e.g. via `predicate_may_hold`
+/// during candidate assembly or projection, are missing from the tree, along with their
+/// nested obligations.
+#[derive(Clone, Debug, Default)]
+pub struct EvaluationTree<'tcx> {
+    pub nodes: Vec<EvaluationTreeNode<'tcx>>,
+    /// Whether some obligations were not recorded because the tree reached its size limit.
+    pub truncated: bool,
+    limit: usize,
+}
+
+#[derive(Clone, Debug)]
+pub struct EvaluationTreeNode<'tcx> {
+    pub predicate: ty::Predicate<'tcx>,
+    pub recursion_depth: usize,
+    /// `None` while `predicate` is still being evaluated.
+    pub result: Option<Result<EvaluationResult, OverflowError>>,
+}
+
+impl<'tcx> EvaluationTree<'tcx> {
+    fn is_full(&self) -> bool {
+        self.nodes.len() >= self.limit
+    }
+
+    fn push(&mut self, predicate: ty::Predicate<'tcx>, recursion_depth: usize) -> Option<usize> {
+        if self.is_full() {
+            self.truncated = true;
+            return None;
+        }
+
+        self.nodes.push(EvaluationTreeNode { predicate, recursion_depth, result: None });
+        Some(self.nodes.len() - 1)
+    }
+}
+
 pub struct SelectionContext<'cx, 'tcx> {
     pub infcx: &'cx InferCtxt<'tcx>,
 
@@ -116,6 +159,11 @@ pub struct SelectionContext<'cx, 'tcx> {
     /// computing it may negatively impact performance.
     intercrate_ambiguity_causes: Option<FxIndexSet<IntercrateAmbiguityCause>>,
 
+    /// If set, we record every obligation we evaluate, together with its result, to
+    /// explain evaluation results in diagnostics and tooling. This is off by default
+    /// as it is only needed once we know that we want to explain a result.
+    evaluation_tree: Option<EvaluationTree<'tcx>>,
+
     /// The mode that trait queries run in, which informs our error handling
     /// policy. In essence, canonicalized queries need their errors propagated
     /// rather than immediately reported because we do not have accurate spans.
@@ -229,6 +277,7 @@ pub fn new(infcx: &'cx InferCtxt<'tcx>) -> SelectionContext<'cx, 'tcx> {
             infcx,
             freshener: infcx.freshener(),
             intercrate_ambiguity_causes: None,
+            evaluation_tree: None,
             query_mode: TraitQueryMode::Standard,
             treat_inductive_cycle: TreatInductiveCycleAs::Recur,
         }
@@ -275,6 +324,40 @@ pub fn take_intercrate_ambiguity_causes(&mut self) -> FxIndexSet<IntercrateAmbig
         self.intercrate_ambiguity_causes.take().unwrap_or_default()
     }
 
+    /// Enables recording the obligations evaluated by this selection context,
+    /// recording at most `limit` of them. See [`EvaluationTree`] for more.
+    pub fn enable_tracking_evaluation_tree(&mut self, limit: usize) {
+        assert!(self.evaluation_tree.is_none());
+        self.evaluation_tree = Some(EvaluationTree { limit, ..Default::default() });
+        debug!("selcx: enable_tracking_evaluation_tree");
+    }
+
+    /// Gets the evaluation tree recorded since tracking was enabled and
+    /// disables tracking at the same time. If tracking is not enabled,
+    /// just returns an empty tree.
+    ///
+    /// Most obligations are evaluated in a probe, so their predicates may refer to
+    /// inference variables which have been rolled back since. These are replaced with
+    /// fresh types and consts, and their regions with `'erased`, without creating any
+    /// new inference variables. The recorded predicates are therefore only meant to be
+    /// displayed.
+    pub fn take_evaluation_tree(&mut self) -> EvaluationTree<'tcx> {
+        let mut tree = self.evaluation_tree.take().unwrap_or_default();
+        // The region constraints may have been taken already, in which case
+        // we can't create a replacer, so don't unless we need one.
+        if tree.nodes.is_empty() {
+            return tree;
+        }
+
+        // Each predicate was resolved at a different point in time, so the same
+        // rolled back variable in two nodes may stand for two unrelated variables.
+        let mut replacer = self.infcx.rolled_back_var_replacer();
+        for node in &mut tree.nodes {
+            node.predicate = replacer.replace(node.predicate);
+        }
+        tree
+    }
+
     pub fn tcx(&self) -> TyCtxt<'tcx> {
         self.infcx.tcx
     }
@@ -654,13 +737,38 @@ fn evaluate_predicates_recursively<'o, I>(
         Ok(result)
     }
 
+    fn evaluate_predicate_recursively<'o>(
+        &mut self,
+        previous_stack: TraitObligationStackList<'o, 'tcx>,
+        obligation: PredicateObligation<'tcx>,
+    ) -> Result<EvaluationResult, OverflowError> {
+        let Some(tree) = &mut self.evaluation_tree else {
+            return self.evaluate_predicate_recursively_inner(previous_stack, obligation);
+        };
+
+        let predicate = obligation.predicate;
+        let node = tree.push(predicate, obligation.recursion_depth);
+        let result = self.evaluate_predicate_recursively_inner(previous_stack, obligation);
+        // Resolve the predicate only now, as evaluating it may have constrained its
+        // inference variables. We're still inside of the probe of our caller, so the
+        // variables created in it still exist.
+        let predicate = self.infcx.resolve_vars_if_possible(predicate);
+        if let Some(node) = node
+            && let Some(tree) = &mut self.evaluation_tree
+        {
+            tree.nodes[node].predicate = predicate;
+            tree.nodes[node].result = Some(result);
+        }
+        result
+    }
+
     #[instrument(
         level = "debug",
         skip(self, previous_stack),
         fields(previous_stack = ?previous_stack.head())
         ret,
     )]
-    fn evaluate_predicate_recursively<'o>(
+    fn evaluate_predicate_recursively_inner<'o>(
         &mut self,
         previous_stack: TraitObligationStackList<'o, 'tcx>,
         obligation: PredicateObligation<'tcx>,
@@ -1328,6 +1436,14 @@ fn check_evaluation_cache(
             return None;
         }
 
+        // A cache hit would hide the nested obligations of `trait_pred`
+        // from the evaluation tree we're recording. Once the tree is full,
+        // we use the caches again, as evaluating the same predicates over
+        // and over again may take exponential time.
+        if self.evaluation_tree.as_ref().is_some_and(|tree| !tree.is_full()) {
+            return None;
+        }
+
         let tcx = self.tcx();
         if self.can_use_global_caches(param_env) {
             if let Some(res) = tcx.evaluation_cache.get(&(param_env, trait_pred), tcx) {
@@ -19,7 +19,7 @@
 mod type_op;
 
 pub use rustc_trait_selection::traits::query::type_op::ascribe_user_type::type_op_ascribe_user_type_with_span;
-pub use type_op::type_op_prove_predicate_with_cause;
+pub use type_op::{type_op_prove_predicate_with_cause, type_op_subtype_with_error};
 
 use rustc_middle::query::Providers;
 
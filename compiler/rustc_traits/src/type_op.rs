@@ -2,6 +2,7 @@
 use rustc_infer::infer::TyCtxtInferExt;
 use rustc_middle::query::Providers;
 use rustc_middle::traits::query::NoSolution;
+use rustc_middle::ty::error::TypeError;
 use rustc_middle::ty::{Clause, ParamEnvAnd};
 use rustc_middle::ty::{FnSig, Lift, PolyFnSig, Ty, TyCtxt, TypeFoldable};
 use rustc_trait_selection::infer::InferCtxtBuilderExt;
@@ -96,11 +97,24 @@ fn type_op_subtype<'tcx>(
     canonicalized: Canonical<'tcx, ParamEnvAnd<'tcx, Subtype<'tcx>>>,
 ) -> Result<&'tcx Canonical<'tcx, QueryResponse<'tcx, ()>>, NoSolution> {
     tcx.infer_ctxt().enter_canonical_trait_query(&canonicalized, |ocx, key| {
-        let (param_env, Subtype { sub, sup }) = key.into_parts();
-        Ok(ocx.sup(&ObligationCause::dummy(), param_env, sup, sub)?)
+        Ok(type_op_subtype_with_error(ocx, key, ObligationCause::dummy())?)
     })
 }
 
+/// The core of the `type_op_subtype` query: for diagnostics purposes in NLL errors, this query
+/// can be re-run to get the `TypeError` of the relation instead of a bare `NoSolution`. Its
+/// expected and found types are the ones at the point where `sub` and `sup` diverge. The
+/// nested obligations of the relation are registered in `ocx`, so their errors still have to
+/// be collected from there. Do not call directly unless you're in that very specific context.
+pub fn type_op_subtype_with_error<'tcx>(
+    ocx: &ObligationCtxt<'_, 'tcx>,
+    key: ParamEnvAnd<'tcx, Subtype<'tcx>>,
+    cause: ObligationCause<'tcx>,
+) -> Result<(), TypeError<'tcx>> {
+    let (param_env, Subtype { sub, sup }) = key.into_parts();
+    ocx.sup(&cause, param_env, sup, sub)
+}
+
 fn type_op_prove_predicate<'tcx>(
     tcx: TyCtxt<'tcx>,
     canonicalized: Canonical<'tcx, ParamEnvAnd<'tcx, ProvePredicate<'tcx>>>,
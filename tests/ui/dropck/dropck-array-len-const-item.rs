@@ -0,0 +1,27 @@
+// check-pass
+// Computing the dtorck constraint of `Foo` must not evaluate the length of
+// its array field. Evaluating `N` borrowchecks its body, which needs the
+// dtorck constraint of `Foo` again.
+
+struct PrintOnDrop<'a>(&'a str);
+
+impl Drop for PrintOnDrop<'_> {
+    fn drop(&mut self) {
+        println!("{}", self.0);
+    }
+}
+
+struct Foo<'a> {
+    _arr: [PrintOnDrop<'a>; N],
+}
+
+impl Drop for Foo<'_> {
+    fn drop(&mut self) {}
+}
+
+const N: usize = {
+    let _x: Option<Foo<'_>> = None;
+    0
+};
+
+fn main() {}
@@ -0,0 +1,58 @@
+// check-pass
+// Dropping an empty array never runs the destructor of its element type,
+// so the borrows held by that element type don't have to be live.
+
+struct PrintOnDrop<'a>(&'a str);
+
+impl Drop for PrintOnDrop<'_> {
+    fn drop(&mut self) {
+        println!("{}", self.0);
+    }
+}
+
+struct Wrapper<'a, const N: usize>([PrintOnDrop<'a>; N]);
+
+struct TupleWrapper<'a, const N: usize>(([PrintOnDrop<'a>; N],));
+
+// The `String` fields need to be dropped, so these are still checked by dropck.
+struct WithDropGlue<'a, const N: usize>(String, [PrintOnDrop<'a>; N]);
+
+fn empty<'a>(_: &'a str) -> [PrintOnDrop<'a>; 0] {
+    []
+}
+
+fn empty_generic<'a, const N: usize>(_: &'a str) -> [PrintOnDrop<'a>; N] {
+    loop {}
+}
+
+fn wrapped<'a>(_: &'a str) -> Wrapper<'a, 0> {
+    Wrapper([])
+}
+
+fn tuple_wrapped<'a>(_: &'a str) -> TupleWrapper<'a, 0> {
+    TupleWrapper(([],))
+}
+
+fn with_drop_glue<'a>(_: &'a str) -> WithDropGlue<'a, 0> {
+    WithDropGlue(String::new(), [])
+}
+
+fn tuple_with_drop_glue<'a>(_: &'a str) -> (String, [PrintOnDrop<'a>; 0]) {
+    (String::new(), [])
+}
+
+fn main() {
+    let _a;
+    let _b;
+    let _c;
+    let _d;
+    let _e;
+    let _f;
+    let s = String::from("temporary");
+    _a = empty(&s);
+    _b = empty_generic::<0>(&s);
+    _c = wrapped(&s);
+    _d = tuple_wrapped(&s);
+    _e = with_drop_glue(&s);
+    _f = tuple_with_drop_glue(&s);
+}
@@ -0,0 +1,26 @@
+// Checks that borrowck names the field whose destructor might use a borrow,
+// when the dropped value doesn't have a destructor of its own.
+
+struct PrintOnDrop<'a>(&'a str);
+
+impl Drop for PrintOnDrop<'_> {
+    fn drop(&mut self) {
+        println!("{}", self.0);
+    }
+}
+
+struct Inner<'a> {
+    name: String,
+    print: PrintOnDrop<'a>,
+}
+
+struct Outer<'a> {
+    inner: (u32, Inner<'a>),
+}
+
+fn main() {
+    let (outer, s);
+    s = String::from("temporary");
+    outer = Outer { inner: (0, Inner { name: String::new(), print: PrintOnDrop(&s) }) };
+}
+//~^^ ERROR `s` does not live long enough
@@ -0,0 +1,47 @@
+// Arrays which aren't empty still drop their elements, so the borrows held by
+// their element type have to be live when the array is dropped.
+
+struct PrintOnDrop<'a>(&'a str);
+
+impl Drop for PrintOnDrop<'_> {
+    fn drop(&mut self) {
+        println!("{}", self.0);
+    }
+}
+
+struct Wrapper<'a, const N: usize>([PrintOnDrop<'a>; N]);
+
+fn one<'a>(s: &'a str) -> [PrintOnDrop<'a>; 1] {
+    [PrintOnDrop(s)]
+}
+
+fn generic<'a, const N: usize>(_: &'a str) -> [PrintOnDrop<'a>; N] {
+    loop {}
+}
+
+fn wrapped<'a>(s: &'a str) -> Wrapper<'a, 1> {
+    Wrapper([PrintOnDrop(s)])
+}
+
+fn array() {
+    let (_a, s);
+    s = String::from("temporary");
+    _a = one(&s);
+}
+//~^^ ERROR `s` does not live long enough
+
+fn generic_array() {
+    let (_a, s);
+    s = String::from("temporary");
+    _a = generic::<1>(&s);
+}
+//~^^ ERROR `s` does not live long enough
+
+fn wrapped_array() {
+    let (_a, s);
+    s = String::from("temporary");
+    _a = wrapped(&s);
+}
+//~^^ ERROR `s` does not live long enough
+
+fn main() {}
@@ -0,0 +1,38 @@
+// Test that the implied bounds of the self type of a `Copy` impl include the
+// ones of its normalized form when checking that its fields are `Copy`.
+
+// check-pass
+
+use std::marker::PhantomData;
+
+trait Tr<'a> {
+    type Out;
+}
+
+impl<'a, T: 'a> Tr<'a> for T {
+    type Out = &'a T;
+}
+
+struct Inner<'a, T>(PhantomData<(&'a (), T)>);
+
+impl<'a, T: 'a> Clone for Inner<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T: 'a> Copy for Inner<'a, T> {}
+
+struct S<'a, T, X>(Inner<'a, T>, PhantomData<X>);
+
+impl<'a, T, X> Clone for S<'a, T, X> {
+    fn clone(&self) -> Self {
+        loop {}
+    }
+}
+
+// `Inner<'a, T>: Copy` requires `T: 'a`, which is only implied by the self
+// type once `<T as Tr<'a>>::Out` is normalized to `&'a T`.
+impl<'a, T> Copy for S<'a, T, <T as Tr<'a>>::Out> {}
+
+fn main() {}
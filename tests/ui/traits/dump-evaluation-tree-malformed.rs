@@ -0,0 +1,14 @@
+#![feature(rustc_attrs)]
+
+trait Foo {}
+
+impl Foo for u8 {}
+
+#[rustc_dump_evaluation_tree(foo)]
+//~^ ERROR expected a single integer limit
+fn test<T: ?Sized + Foo>() {}
+
+fn main() {
+    test::<u8>();
+    //~^ ERROR evaluate(Binder { value: TraitPredicate(<u8 as Foo>, polarity:Positive), bound_vars: [] }) = Ok(EvaluatedToOk)
+}
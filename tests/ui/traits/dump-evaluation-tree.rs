@@ -0,0 +1,25 @@
+#![feature(rustc_attrs)]
+
+// Checks the evaluation trees recorded by `evaluate_obligation_with_tree`,
+// including the note we emit when a tree was truncated.
+
+trait Foo {}
+
+struct Wrap<T>(T);
+
+impl<T: Foo> Foo for Wrap<T> {}
+
+impl Foo for u8 {}
+
+#[rustc_dump_evaluation_tree]
+fn test<T: ?Sized + Foo>() {}
+
+#[rustc_dump_evaluation_tree(2)]
+fn test_truncated<T: ?Sized + Foo>() {}
+
+fn main() {
+    test::<Wrap<Wrap<u8>>>();
+    //~^ ERROR evaluate(Binder { value: TraitPredicate(<Wrap<Wrap<u8>> as Foo>, polarity:Positive), bound_vars: [] }) = Ok(EvaluatedToOk)
+    test_truncated::<Wrap<Wrap<u8>>>();
+    //~^ ERROR evaluate(Binder { value: TraitPredicate(<Wrap<Wrap<u8>> as Foo>, polarity:Positive), bound_vars: [] }) = Ok(EvaluatedToOk)
+}
@@ -0,0 +1,17 @@
+// compile-flags: -Ztrait-solver=next
+
+#![feature(rustc_attrs)]
+
+// The new solver doesn't record evaluation trees.
+
+trait Foo {}
+
+impl Foo for u8 {}
+
+#[rustc_dump_evaluation_tree]
+fn test<T: ?Sized + Foo>() {}
+
+fn main() {
+    test::<u8>();
+    //~^ ERROR evaluate(Binder { value: TraitPredicate(<u8 as Foo>, polarity:Positive), bound_vars: [] }) = Ok(EvaluatedToOk)
+}